@@ -13,9 +13,10 @@
 //! }
 //!
 //! impl<T> Reducable<T> for A<T> {
-//!     fn reduce_function<R>(&self, fnc: fn(acc: R, cur: &T) -> R, initial: R) -> R
+//!     fn reduce_function<R, F: FnMut(R, &T) -> R>(&self, mut fnc: F, initial: R) -> R
 //!     {
-//!         fnc(fnc(initial, &self.value1), &self.value2)
+//!         let acc = fnc(initial, &self.value1);
+//!         fnc(acc, &self.value2)
 //!     }
 //! }
 //!
@@ -28,6 +29,9 @@
 //! assert_eq!(sum, 3)
 //!```
 
+use std::collections::HashMap;
+use std::ops::Range;
+
 /// Reducable
 pub trait Reducable<T> {
     /// The reduce function to be called
@@ -40,7 +44,7 @@ pub trait Reducable<T> {
     /// let sum = vec_sum.reduce(|acc, cur| -> i32 {acc + cur}, None);
     /// assert_eq!(sum, 15)
     /// ```
-    fn reduce<R>(&self, fnc: fn(acc: R, cur: &T) -> R, initial: Option<R>) -> R
+    fn reduce<R, F: FnMut(R, &T) -> R>(&self, fnc: F, initial: Option<R>) -> R
     where
         R: Default,
     {
@@ -49,25 +53,331 @@ pub trait Reducable<T> {
     }
 
     /// The function to define the reduce logic for a given structure
-    fn reduce_function<R>(&self, fnc: fn(acc: R, cur: &T) -> R, initial_value: R) -> R;
+    fn reduce_function<R, F: FnMut(R, &T) -> R>(&self, fnc: F, initial_value: R) -> R;
+
+    /// Like [`Reducable::reduce`], but without a seed value.
+    ///
+    /// The first element becomes the initial accumulator, so `T` does not need
+    /// to implement `Default`. Returns `None` if the collection is empty,
+    /// rather than silently returning a bogus zero value for operations (like
+    /// `max`/`min`/string-join) where no zero value is valid.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use reduce::Reducable;
+    ///
+    /// let max = vec![3, 7, 2].reduce_opt(|acc, cur| -> i32 { if *cur > acc { *cur } else { acc } });
+    /// assert_eq!(max, Some(7));
+    ///
+    /// let empty: Vec<i32> = vec![];
+    /// assert_eq!(empty.reduce_opt(|acc, cur| acc + cur), None);
+    /// ```
+    fn reduce_opt<F: FnMut(T, &T) -> T>(&self, mut fnc: F) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.reduce_function(
+            |acc: Option<T>, cur: &T| match acc {
+                Some(prev) => Some(fnc(prev, cur)),
+                None => Some(cur.clone()),
+            },
+            None,
+        )
+    }
+
+    /// Reduces the collection, potentially across multiple threads.
+    ///
+    /// The default implementation just forwards to [`Reducable::reduce_function`]
+    /// sequentially; implementors that can split themselves into independent
+    /// chunks (e.g. the `Vec<T>` impl below) override this to fold each chunk
+    /// down to a single value with its own [`StreamingReduce`] and combine the
+    /// per-chunk results with [`Mergeable::merge`]. Either way, `fnc` must be
+    /// associative and `merge` must agree with sequential `add` calls, since
+    /// chunk boundaries are otherwise invisible to the result; non-associative
+    /// functions (e.g. subtraction) will produce a result that depends on how
+    /// the collection happened to be chunked.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use reduce::Reducable;
+    ///
+    /// let sum = vec![1, 2, 3, 4, 5].par_reduce(|acc, cur| acc + cur, 0);
+    /// assert_eq!(sum, 15)
+    /// ```
+    fn par_reduce<F>(&self, fnc: F, initial: T) -> T
+    where
+        F: Fn(T, &T) -> T + Send + Sync + Clone,
+        T: Clone + Send + Sync,
+    {
+        self.reduce_function(|acc, cur| fnc(acc, cur), initial)
+    }
+}
+
+/// A constant-memory, incrementally-updated reduction.
+///
+/// Holds the current accumulator for an *associative* combining function, so
+/// it can be folded one element at a time (e.g. over a stream you don't want
+/// to materialize) and two independently-accumulated `StreamingReduce`s can
+/// later be combined with [`Mergeable::merge`]. As with [`Reducable::par_reduce`],
+/// the combining function must be associative for `merge` to agree with
+/// folding the same elements sequentially.
+pub struct StreamingReduce<T, F>
+where
+    F: FnMut(T, &T) -> T,
+{
+    acc: T,
+    fnc: F,
+}
+
+impl<T, F> StreamingReduce<T, F>
+where
+    T: Clone,
+    F: FnMut(T, &T) -> T,
+{
+    /// Creates a new `StreamingReduce` seeded with `initial`.
+    pub fn new(initial: T, fnc: F) -> Self {
+        StreamingReduce { acc: initial, fnc }
+    }
+}
+
+/// A constant-memory accumulator that can be folded incrementally and merged
+/// with another of its own kind, as [`StreamingReduce`] does for `Reducable`.
+pub trait Mergeable<T> {
+    /// Folds one new element into the accumulator.
+    fn add(&mut self, x: &T);
+
+    /// Reads the current accumulated value without consuming it.
+    fn estimate(&self) -> &T;
+
+    /// Combines another, independently-accumulated instance into this one.
+    ///
+    /// `other` must have been built with an equivalent (associative)
+    /// combining function, or the result is meaningless.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use reduce::{Mergeable, StreamingReduce};
+    ///
+    /// let sum = |acc, cur: &i32| acc + cur;
+    /// let mut first_half = StreamingReduce::new(0, sum);
+    /// first_half.add(&1);
+    /// first_half.add(&2);
+    ///
+    /// let mut second_half = StreamingReduce::new(0, sum);
+    /// second_half.add(&3);
+    ///
+    /// first_half.merge(&second_half);
+    /// assert_eq!(*first_half.estimate(), 6);
+    /// ```
+    fn merge(&mut self, other: &Self);
+}
+
+impl<T, F> Mergeable<T> for StreamingReduce<T, F>
+where
+    T: Clone,
+    F: FnMut(T, &T) -> T,
+{
+    fn add(&mut self, x: &T) {
+        let current = self.acc.clone();
+        self.acc = (self.fnc)(current, x);
+    }
+
+    fn estimate(&self) -> &T {
+        &self.acc
+    }
+
+    fn merge(&mut self, other: &Self) {
+        let current = self.acc.clone();
+        self.acc = (self.fnc)(current, other.estimate());
+    }
 }
 
 fn get_initial<R>(initial: Option<R>) -> R
 where
     R: Default,
 {
-    match initial {
-        Some(x) => x,
-        None => Default::default(),
-    }
+    initial.unwrap_or_default()
 }
 
+/// Containers below each get a direct `Reducable` impl rather than one
+/// blanket impl over `IntoIterator`: a single `impl<C, T> Reducable<T> for C
+/// where for<'a> &'a C: IntoIterator<Item = &'a T>` looks appealing, but
+/// `rustc`'s coherence checker rejects it as soon as any concrete foreign
+/// type (like `HashMap` or `Range<T>`) also gets its own impl of this trait,
+/// since it can't rule out some future standard-library `IntoIterator` impl
+/// making the two overlap. Plain per-container impls sidestep that.
 impl<T> Reducable<T> for Vec<T> {
-    fn reduce_function<R>(&self, fnc: fn(acc: R, cur: &T) -> R, initial_value: R) -> R {
+    fn reduce_function<R, F: FnMut(R, &T) -> R>(&self, mut fnc: F, initial_value: R) -> R {
         let mut return_value = initial_value;
         for x in self {
             return_value = fnc(return_value, x);
         }
         return_value
     }
+
+    fn par_reduce<F>(&self, fnc: F, initial: T) -> T
+    where
+        F: Fn(T, &T) -> T + Send + Sync + Clone,
+        T: Clone + Send + Sync,
+    {
+        if self.is_empty() {
+            return initial;
+        }
+
+        let num_threads = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        let chunk_size = (self.len() / num_threads).max(1);
+
+        let partials: Vec<T> = std::thread::scope(|scope| {
+            let handles: Vec<_> = self
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    let fnc = fnc.clone();
+                    scope.spawn(move || {
+                        let mut chunk_iter = chunk.iter();
+                        let first = chunk_iter.next().expect("chunks are never empty").clone();
+                        let mut partition = StreamingReduce::new(first, fnc);
+                        for x in chunk_iter {
+                            partition.add(x);
+                        }
+                        partition.estimate().clone()
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        let mut combined = StreamingReduce::new(initial, fnc.clone());
+        for partial in partials {
+            combined.merge(&StreamingReduce::new(partial, fnc.clone()));
+        }
+        combined.estimate().clone()
+    }
+}
+
+/// # Examples
+/// ```rust
+/// use reduce::Reducable;
+///
+/// let array_sum = [1, 2, 3, 4, 5].reduce(|acc, cur| -> i32 {acc + cur}, None);
+/// assert_eq!(array_sum, 15)
+/// ```
+impl<T, const N: usize> Reducable<T> for [T; N] {
+    fn reduce_function<R, F: FnMut(R, &T) -> R>(&self, mut fnc: F, initial_value: R) -> R {
+        let mut return_value = initial_value;
+        for x in self {
+            return_value = fnc(return_value, x);
+        }
+        return_value
+    }
+}
+
+/// `[T]` is unsized, so `&self` is the only receiver that works here.
+///
+/// # Examples
+/// ```rust
+/// use reduce::Reducable;
+///
+/// let arr = [1, 2, 3, 4, 5];
+/// let slice: &[i32] = &arr;
+/// let sum = slice.reduce(|acc, cur| -> i32 {acc + cur}, None);
+/// assert_eq!(sum, 15)
+/// ```
+impl<T> Reducable<T> for [T] {
+    fn reduce_function<R, F: FnMut(R, &T) -> R>(&self, mut fnc: F, initial_value: R) -> R {
+        let mut return_value = initial_value;
+        for x in self {
+            return_value = fnc(return_value, x);
+        }
+        return_value
+    }
+}
+
+impl<T> Reducable<T> for Option<T> {
+    fn reduce_function<R, F: FnMut(R, &T) -> R>(&self, mut fnc: F, initial_value: R) -> R {
+        match self {
+            Some(x) => fnc(initial_value, x),
+            None => initial_value,
+        }
+    }
+}
+
+impl<T> Reducable<T> for std::collections::HashSet<T> {
+    fn reduce_function<R, F: FnMut(R, &T) -> R>(&self, mut fnc: F, initial_value: R) -> R {
+        let mut return_value = initial_value;
+        for x in self {
+            return_value = fnc(return_value, x);
+        }
+        return_value
+    }
+}
+
+impl<T> Reducable<T> for std::collections::BTreeSet<T> {
+    fn reduce_function<R, F: FnMut(R, &T) -> R>(&self, mut fnc: F, initial_value: R) -> R {
+        let mut return_value = initial_value;
+        for x in self {
+            return_value = fnc(return_value, x);
+        }
+        return_value
+    }
+}
+
+/// `HashMap` iterates as `(&K, &V)` rather than a single `&T`. Reduce over
+/// owned `(K, V)` pairs instead.
+///
+/// # Examples
+/// ```rust
+/// use reduce::Reducable;
+/// use std::collections::HashMap;
+///
+/// let mut prices = HashMap::new();
+/// prices.insert("apple", 1);
+/// prices.insert("pear", 2);
+///
+/// let total = prices.reduce(|acc: i32, (_, price)| acc + price, None);
+/// assert_eq!(total, 3)
+/// ```
+impl<K, V> Reducable<(K, V)> for HashMap<K, V>
+where
+    K: Clone,
+    V: Clone,
+{
+    fn reduce_function<R, F: FnMut(R, &(K, V)) -> R>(&self, mut fnc: F, initial_value: R) -> R {
+        let mut return_value = initial_value;
+        for (key, value) in self {
+            return_value = fnc(return_value, &(key.clone(), value.clone()));
+        }
+        return_value
+    }
+}
+
+/// `Range<T>` has no `IntoIterator` impl for `&Range<T>`; clone the range
+/// itself and drive the owned iterator that `Range<T>: Iterator` already
+/// provides.
+///
+/// `Range<T>` is itself a (by-value) `std::iter::Iterator`, which already has
+/// its own inherent `reduce` method, so `(1..6).reduce(...)` resolves to
+/// that one, not this trait's. Call through `Reducable::reduce` directly to
+/// reach this impl.
+///
+/// # Examples
+/// ```rust
+/// use reduce::Reducable;
+///
+/// let sum = Reducable::reduce(&(1..6), |acc, cur| -> i32 {acc + cur}, None);
+/// assert_eq!(sum, 15)
+/// ```
+impl<T> Reducable<T> for Range<T>
+where
+    T: Clone,
+    Range<T>: Iterator<Item = T>,
+{
+    fn reduce_function<R, F: FnMut(R, &T) -> R>(&self, mut fnc: F, initial_value: R) -> R {
+        let mut return_value = initial_value;
+        for x in self.clone() {
+            return_value = fnc(return_value, &x);
+        }
+        return_value
+    }
 }